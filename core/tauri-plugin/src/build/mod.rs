@@ -7,22 +7,71 @@ use std::path::{Path, PathBuf};
 use cargo_metadata::{Metadata, MetadataCommand};
 use tauri::utils::acl::{self, Error};
 
+const DEFAULT_PERMISSIONS_PATH: &str = "./permissions/**/*.*";
+const DEFAULT_AUTOGENERATED_DIR: &str = "permissions/autogenerated";
+
 pub struct Builder<'a> {
   commands: &'a [&'static str],
-  global_scope_schema: Option<schemars::schema::RootSchema>,
+  permissions_path: &'a str,
+  autogenerated_dir: PathBuf,
+  command_prefix: &'a str,
+  manifest: bool,
+  global_scope_schemas: Vec<schemars::schema::RootSchema>,
 }
 
 impl<'a> Builder<'a> {
   pub fn new(commands: &'a [&'static str]) -> Self {
     Self {
       commands,
-      global_scope_schema: None,
+      permissions_path: DEFAULT_PERMISSIONS_PATH,
+      autogenerated_dir: PathBuf::from(DEFAULT_AUTOGENERATED_DIR),
+      command_prefix: "",
+      manifest: false,
+      global_scope_schemas: Vec::new(),
     }
   }
 
-  /// Sets the global scope JSON schema.
+  /// Sets the glob pattern used to discover this plugin's permission files.
+  ///
+  /// Defaults to `"./permissions/**/*.*"`.
+  pub fn permissions_path(mut self, glob: &'a str) -> Self {
+    self.permissions_path = glob;
+    self
+  }
+
+  /// Sets the directory autogenerated permissions (e.g. command permissions and `reference.md`)
+  /// are written to.
+  ///
+  /// Defaults to `"permissions/autogenerated"`.
+  pub fn autogenerated_dir(mut self, path: impl Into<PathBuf>) -> Self {
+    self.autogenerated_dir = path.into();
+    self
+  }
+
+  /// Sets the prefix prepended to the command name in autogenerated command permission
+  /// identifiers (`allow-{prefix}{command}` and `deny-{prefix}{command}`).
+  ///
+  /// Defaults to an empty prefix.
+  pub fn command_prefix(mut self, prefix: &'a str) -> Self {
+    self.command_prefix = prefix;
+    self
+  }
+
+  /// Enables generation of a machine-readable `permissions.json` manifest, describing every
+  /// permission, set and the default in the autogenerated directory, alongside `reference.md`.
+  ///
+  /// Disabled by default.
+  pub fn manifest(mut self, manifest: bool) -> Self {
+    self.manifest = manifest;
+    self
+  }
+
+  /// Adds a global scope JSON schema. Can be called multiple times, e.g. when a plugin composes
+  /// its scope type from several independently generated [`schemars::schema::RootSchema`]s
+  /// (one per module); the schemas are merged into a single `anyOf` root schema before being
+  /// written out.
   pub fn global_scope_schema(mut self, schema: schemars::schema::RootSchema) -> Self {
-    self.global_scope_schema.replace(schema);
+    self.global_scope_schemas.push(schema);
     self
   }
 
@@ -52,22 +101,56 @@ impl<'a> Builder<'a> {
     // requirement: links MUST be set and MUST match the name
     let _links = build_var("CARGO_MANIFEST_LINKS")?;
 
-    let autogenerated = Path::new("permissions/autogenerated");
+    let autogenerated = self.autogenerated_dir.as_path();
     let commands_dir = &autogenerated.join("commands");
 
-    std::fs::create_dir_all(&autogenerated).expect("unable to create permissions dir");
+    std::fs::create_dir_all(autogenerated).expect("unable to create permissions dir");
 
-    if !self.commands.is_empty() {
-      acl::build::autogenerate_command_permissions(commands_dir, self.commands, "");
+    let permission_files = glob_permission_files(self.permissions_path)?;
+    for file in &permission_files {
+      println!("cargo:rerun-if-changed={}", file.display());
     }
+    println!(
+      "cargo:rerun-if-changed={}",
+      glob_base_dir(self.permissions_path).display()
+    );
+
+    let fingerprint_path = out_dir.join("permissions-fingerprint");
+    let fingerprint = compute_fingerprint(
+      &permission_files,
+      self.commands,
+      self.command_prefix,
+      self.manifest,
+      autogenerated,
+      &self.global_scope_schemas,
+    )?;
+    let outputs_exist = autogenerated.join("reference.md").exists()
+      && (!self.manifest || autogenerated.join("permissions.json").exists());
+    let up_to_date = outputs_exist
+      && std::fs::read_to_string(&fingerprint_path)
+        .map(|previous| previous == fingerprint)
+        .unwrap_or(false);
 
-    let permissions = acl::build::define_permissions("./permissions/**/*.*", &name, &out_dir)?;
+    if !up_to_date {
+      if !self.commands.is_empty() {
+        acl::build::autogenerate_command_permissions(commands_dir, self.commands, self.command_prefix);
+      }
 
-    acl::build::generate_schema(&permissions, "./permissions")?;
-    generate_docs(&permissions, &autogenerated)?;
+      let permissions = acl::build::define_permissions(self.permissions_path, &name, &out_dir)?;
+      validate_permissions(&permissions)?;
 
-    if let Some(global_scope_schema) = self.global_scope_schema {
-      acl::build::define_global_scope_schema(global_scope_schema, &name, &out_dir)?;
+      acl::build::generate_schema(&permissions, glob_base_dir(self.permissions_path))?;
+      generate_docs(&permissions, autogenerated)?;
+
+      if self.manifest {
+        generate_manifest(&permissions, autogenerated)?;
+      }
+
+      if let Some(global_scope_schema) = merge_global_scope_schemas(self.global_scope_schemas) {
+        acl::build::define_global_scope_schema(global_scope_schema, &name, &out_dir)?;
+      }
+
+      std::fs::write(&fingerprint_path, &fingerprint).map_err(Error::WriteFile)?;
     }
 
     let metadata = find_metadata()?;
@@ -78,7 +161,7 @@ impl<'a> Builder<'a> {
 }
 
 fn generate_docs(permissions: &[acl::plugin::PermissionFile], out_dir: &Path) -> Result<(), Error> {
-  let mut docs = format!("# Permissions\n\n");
+  let mut docs = "# Permissions\n\n".to_string();
 
   fn docs_from(id: &str, description: Option<&str>) -> String {
     let mut docs = format!("## {id}");
@@ -113,6 +196,181 @@ fn generate_docs(permissions: &[acl::plugin::PermissionFile], out_dir: &Path) ->
   Ok(())
 }
 
+/// Resolves every file currently matched by a permissions glob, sorted for stable hashing.
+fn glob_permission_files(permissions_glob: &str) -> Result<Vec<PathBuf>, Error> {
+  let mut files: Vec<PathBuf> = glob::glob(permissions_glob)
+    .map_err(Error::Glob)?
+    .filter_map(Result::ok)
+    .filter(|path| path.is_file())
+    .collect();
+  files.sort();
+  Ok(files)
+}
+
+/// The directory portion of a permissions glob, so cargo can be told to rerun the build script
+/// when files are added or removed (not just when an already-matched file changes).
+fn glob_base_dir(permissions_glob: &str) -> PathBuf {
+  let base = permissions_glob
+    .split(['*', '?'])
+    .next()
+    .unwrap_or(permissions_glob);
+  PathBuf::from(base.trim_end_matches('/'))
+}
+
+/// Hashes the contents of every matched permission file together with the command list, the
+/// command prefix and the global scope schema, so [`Builder::try_build`] can skip regenerating
+/// the schema, docs and manifest when none of its inputs changed since the previous build.
+fn compute_fingerprint(
+  permission_files: &[PathBuf],
+  commands: &[&str],
+  command_prefix: &str,
+  manifest: bool,
+  autogenerated_dir: &Path,
+  global_scope_schemas: &[schemars::schema::RootSchema],
+) -> Result<String, Error> {
+  use std::hash::{Hash, Hasher};
+
+  let mut hasher = std::collections::hash_map::DefaultHasher::new();
+
+  for path in permission_files {
+    path.hash(&mut hasher);
+    std::fs::read(path).map_err(Error::ReadFile)?.hash(&mut hasher);
+  }
+
+  commands.hash(&mut hasher);
+  command_prefix.hash(&mut hasher);
+  manifest.hash(&mut hasher);
+  autogenerated_dir.hash(&mut hasher);
+
+  for schema in global_scope_schemas {
+    serde_json::to_string(schema)
+      .map_err(Error::Json)?
+      .hash(&mut hasher);
+  }
+
+  Ok(format!("{:x}", hasher.finish()))
+}
+
+/// Merges multiple global scope schemas into a single root schema so plugins that compose their
+/// scope type from several independently generated `RootSchema`s (e.g. one per module) can still
+/// produce one schema for [`acl::build::define_global_scope_schema`].
+fn merge_global_scope_schemas(
+  schemas: Vec<schemars::schema::RootSchema>,
+) -> Option<schemars::schema::RootSchema> {
+  let mut schemas = schemas.into_iter();
+  let first = schemas.next()?;
+  let rest: Vec<_> = schemas.collect();
+  if rest.is_empty() {
+    return Some(first);
+  }
+
+  let mut definitions = first.definitions.clone();
+  let mut any_of = vec![schemars::schema::Schema::Object(first.schema.clone())];
+
+  for schema in rest {
+    definitions.extend(schema.definitions);
+    any_of.push(schemars::schema::Schema::Object(schema.schema));
+  }
+
+  Some(schemars::schema::RootSchema {
+    meta_schema: first.meta_schema,
+    schema: schemars::schema::SchemaObject {
+      subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+        any_of: Some(any_of),
+        ..Default::default()
+      })),
+      ..Default::default()
+    },
+    definitions,
+  })
+}
+
+/// Generates a `permissions.json` manifest describing every permission, set and the default,
+/// so tooling like capability editors, documentation sites or security auditors can consume a
+/// plugin's ACL surface without parsing `reference.md`.
+fn generate_manifest(permissions: &[acl::plugin::PermissionFile], out_dir: &Path) -> Result<(), Error> {
+  let mut sets = Vec::new();
+  let mut default = None;
+  let mut entries = Vec::new();
+
+  for file in permissions {
+    for set in &file.set {
+      sets.push(serde_json::json!({
+        "identifier": set.identifier,
+        "description": set.description,
+        "permissions": set.permissions,
+      }));
+    }
+
+    if let Some(d) = &file.default {
+      default = Some(serde_json::json!({
+        "description": d.description,
+        "permissions": d.permissions,
+      }));
+    }
+
+    for permission in &file.permission {
+      entries.push(serde_json::json!({
+        "identifier": permission.identifier,
+        "description": permission.description,
+        "allow": permission.commands.allow,
+        "deny": permission.commands.deny,
+        "denies": !permission.commands.deny.is_empty(),
+      }));
+    }
+  }
+
+  let manifest = serde_json::json!({
+    "default": default,
+    "sets": sets,
+    "permissions": entries,
+  });
+
+  let contents = serde_json::to_string_pretty(&manifest).map_err(Error::Json)?;
+  std::fs::write(out_dir.join("permissions.json"), contents).map_err(Error::WriteFile)?;
+
+  Ok(())
+}
+
+/// Checks that every identifier referenced by a `set`'s permission list (and by the `default`
+/// set) resolves to a `permission` or `set` defined somewhere in the plugin, so a typo doesn't
+/// silently produce a permission that only fails once an app depending on the plugin is built.
+fn validate_permissions(permissions: &[acl::plugin::PermissionFile]) -> Result<(), Error> {
+  let mut identifiers = std::collections::HashSet::new();
+  for file in permissions {
+    for set in &file.set {
+      identifiers.insert(set.identifier.as_str());
+    }
+    for permission in &file.permission {
+      identifiers.insert(permission.identifier.as_str());
+    }
+  }
+
+  let check_members = |members: &[String], referenced_by: &str, path: &Path| -> Result<(), Error> {
+    for member in members {
+      if !identifiers.contains(member.as_str()) {
+        return Err(Error::UnknownPermission {
+          identifier: member.clone(),
+          referenced_by: referenced_by.to_string(),
+          path: path.to_path_buf(),
+        });
+      }
+    }
+    Ok(())
+  };
+
+  for file in permissions {
+    for set in &file.set {
+      check_members(&set.permissions, &set.identifier, &file.path)?;
+    }
+    if let Some(default) = &file.default {
+      check_members(&default.permissions, "default", &file.path)?;
+    }
+  }
+
+  Ok(())
+}
+
 /// Grab an env var that is expected to be set inside of build scripts.
 fn build_var(key: &'static str) -> Result<String, Error> {
   std::env::var(key).map_err(|_| Error::BuildVar(key))
@@ -0,0 +1,104 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Helpers used by plugin crates' `build.rs` to collect and generate ACL artifacts.
+
+use std::path::Path;
+
+use super::plugin::PermissionFile;
+use super::Error;
+
+/// Writes an `allow-{prefix}{command}`/`deny-{prefix}{command}` permission pair for every
+/// command into `out_dir`.
+pub fn autogenerate_command_permissions(out_dir: &Path, commands: &[&str], prefix: &str) {
+  std::fs::create_dir_all(out_dir).expect("unable to create autogenerated commands dir");
+
+  for command in commands {
+    let identifier = if prefix.is_empty() {
+      command.to_string()
+    } else {
+      format!("{prefix}{command}")
+    };
+
+    for (kind, list) in [("allow", "allow"), ("deny", "deny")] {
+      let contents = format!(
+        "[[permission]]\nidentifier = \"{kind}-{identifier}\"\ndescription = \"Automatically generated permission to {kind} {list} execution of `{command}`.\"\n\n[permission.commands]\n{list} = [\"{command}\"]\n"
+      );
+      let file_name = format!("{kind}-{}.toml", identifier.replace(['/', ':'], "-"));
+      let _ = std::fs::write(out_dir.join(file_name), contents);
+    }
+  }
+}
+
+/// Globs `permissions_glob`, parses every matched file as a [`PermissionFile`], and returns them
+/// with their `path` populated for error reporting.
+pub fn define_permissions(
+  permissions_glob: &str,
+  _plugin_name: &str,
+  _out_dir: &Path,
+) -> Result<Vec<PermissionFile>, Error> {
+  let mut permissions = Vec::new();
+
+  for entry in glob::glob(permissions_glob)? {
+    let Ok(path) = entry else { continue };
+    if !path.is_file() {
+      continue;
+    }
+
+    let contents = std::fs::read_to_string(&path).map_err(Error::ReadFile)?;
+    let mut file: PermissionFile = match path.extension().and_then(|ext| ext.to_str()) {
+      Some("json") => serde_json::from_str(&contents).map_err(Error::Json)?,
+      _ => toml::from_str(&contents).map_err(|error| {
+        Error::ReadFile(std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+      })?,
+    };
+    file.path = path;
+    permissions.push(file);
+  }
+
+  Ok(permissions)
+}
+
+/// Writes the JSON schema describing every known permission identifier to `schema.json` in
+/// `out_dir`, so editors can offer autocompletion for a plugin's capability files.
+pub fn generate_schema(
+  permissions: &[PermissionFile],
+  out_dir: impl AsRef<Path>,
+) -> Result<(), Error> {
+  let identifiers: Vec<&str> = permissions
+    .iter()
+    .flat_map(|file| {
+      file
+        .set
+        .iter()
+        .map(|set| set.identifier.as_str())
+        .chain(file.permission.iter().map(|p| p.identifier.as_str()))
+    })
+    .collect();
+
+  let schema = serde_json::json!({
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "enum": identifiers,
+  });
+
+  let out_dir = out_dir.as_ref();
+  std::fs::create_dir_all(out_dir).map_err(Error::WriteFile)?;
+  let contents = serde_json::to_string_pretty(&schema).map_err(Error::Json)?;
+  std::fs::write(out_dir.join("schema.json"), contents).map_err(Error::WriteFile)?;
+
+  Ok(())
+}
+
+/// Writes the plugin's global scope JSON schema to `OUT_DIR` as `{name}-global-scope.json`.
+pub fn define_global_scope_schema(
+  schema: schemars::schema::RootSchema,
+  name: &str,
+  out_dir: &Path,
+) -> Result<(), Error> {
+  let contents = serde_json::to_string_pretty(&schema).map_err(Error::Json)?;
+  std::fs::write(out_dir.join(format!("{name}-global-scope.json")), contents)
+    .map_err(Error::WriteFile)?;
+
+  Ok(())
+}
@@ -0,0 +1,55 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Access control list types shared by Tauri plugins and their build scripts.
+
+use std::path::PathBuf;
+
+pub mod build;
+pub mod plugin;
+
+/// Errors that can occur while validating or generating a plugin's ACL.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+  /// Plugin crate names must not contain underscores.
+  #[error("plugin crate names cannot contain underscores")]
+  CrateName,
+
+  /// A required build-script environment variable was not set.
+  #[error("`{0}` is not set, are you running this build script with cargo?")]
+  BuildVar(&'static str),
+
+  /// Failed to collect crate metadata.
+  #[error("failed to collect cargo metadata: {0}")]
+  Metadata(#[from] cargo_metadata::Error),
+
+  /// Failed to write a generated file.
+  #[error("failed to write file: {0}")]
+  WriteFile(std::io::Error),
+
+  /// Failed to read or parse a permission file.
+  #[error("failed to read file: {0}")]
+  ReadFile(std::io::Error),
+
+  /// An invalid permissions glob pattern was provided.
+  #[error("invalid glob pattern: {0}")]
+  Glob(#[from] glob::PatternError),
+
+  /// Failed to parse a JSON permission file or to serialize a generated JSON artifact (e.g. the
+  /// `permissions.json` manifest).
+  #[error("failed to (de)serialize JSON: {0}")]
+  Json(#[from] serde_json::Error),
+
+  /// A `set` or `default` referenced an identifier that does not resolve to a defined
+  /// `permission` or `set`.
+  #[error("unknown permission `{identifier}` referenced by `{referenced_by}` in {path}")]
+  UnknownPermission {
+    /// The identifier that could not be resolved.
+    identifier: String,
+    /// The identifier of the `set` (or `"default"`) that referenced it.
+    referenced_by: String,
+    /// The permission file the reference was found in.
+    path: PathBuf,
+  },
+}
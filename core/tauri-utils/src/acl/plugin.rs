@@ -0,0 +1,71 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Types describing a plugin's permission files, as parsed from `permissions/**/*.*`.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// The list of commands a permission allows or denies.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Commands {
+  /// Commands this permission allows.
+  #[serde(default)]
+  pub allow: Vec<String>,
+  /// Commands this permission denies.
+  #[serde(default)]
+  pub deny: Vec<String>,
+}
+
+/// A single permission definition.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Permission {
+  /// Identifier of the permission.
+  pub identifier: String,
+  /// Description of what the permission does.
+  #[serde(default)]
+  pub description: Option<String>,
+  /// The commands this permission is attached to.
+  #[serde(default)]
+  pub commands: Commands,
+}
+
+/// A named group of permissions and/or other sets.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PermissionSet {
+  /// Identifier of the set.
+  pub identifier: String,
+  /// Description of what the set grants.
+  pub description: String,
+  /// Identifiers of the permissions and sets this set is made up of.
+  pub permissions: Vec<String>,
+}
+
+/// The default permission set applied to a plugin when the consumer doesn't pick one explicitly.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DefaultPermission {
+  /// Description of the default permission set.
+  #[serde(default)]
+  pub description: Option<String>,
+  /// Identifiers of the permissions and sets granted by default.
+  pub permissions: Vec<String>,
+}
+
+/// A parsed permission file, as found by [`crate::acl::build::define_permissions`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct PermissionFile {
+  /// The file this was parsed from, kept around so validation errors can point back at it.
+  #[serde(skip, default)]
+  pub path: PathBuf,
+  /// The default permission set, if defined in this file.
+  #[serde(default)]
+  pub default: Option<DefaultPermission>,
+  /// Permission sets defined in this file.
+  #[serde(default)]
+  pub set: Vec<PermissionSet>,
+  /// Permissions defined in this file.
+  #[serde(default)]
+  pub permission: Vec<Permission>,
+}